@@ -5,6 +5,8 @@ use DecodeError;
 use decoder::*;
 use encoder;
 
+pub mod constants;
+
 pub trait Alphabet {
     #[cfg(feature = "alloc")]
     fn encode(self, input: &[u8]) -> String;
@@ -53,7 +55,7 @@ impl<'a> Alphabet for &[u8] {
     fn decode_mut<const BACKING: usize>(self, output: &mut [u8], input: &str) -> Result<(), DecodeError> {
         match U8Decoder::new(self).decode_mut::<BACKING>(output, input) {
             Ok(_) => return Ok(()),
-            Err(_) => return Err(DecodeError)
+            Err(_) => return Err(DecodeError::InvalidChar)
         }
     }
 }
@@ -80,7 +82,7 @@ impl<'a> Alphabet for &str {
             U8Decoder::new(self.as_bytes()).decode(input)
         } else {
             let alphabet: Vec<char> = self.chars().collect();
-            CharDecoder(&alphabet).decode(input)
+            CharDecoder::new(&alphabet).decode(input)
         }
     }
 
@@ -101,7 +103,7 @@ impl<'a> Alphabet for &str {
     fn decode_mut<const BACKING: usize>(self, output: &mut [u8], input: &str) -> Result<(), DecodeError> {
         match U8Decoder::new(self.as_bytes()).decode_mut::<BACKING>(output, input) {
             Ok(_) => return Ok(()),
-            Err(_) => return Err(DecodeError)
+            Err(_) => return Err(DecodeError::InvalidChar)
         }
     }
 }
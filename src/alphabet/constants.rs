@@ -0,0 +1,52 @@
+//! Ready-made `&'static str` alphabets for the most common bases.
+//!
+//! These plug straight into the `Alphabet for &str` impl, so e.g.
+//! `base_x::encode(alphabet::constants::BASE58_BITCOIN, data)` just works.
+//!
+//! Note on leading zeros: for every alphabet here the character at index `0`
+//! is treated as the "zero" digit, so runs of leading zero bytes in the
+//! input round-trip as runs of that character in the output. This matters
+//! for ordering-sensitive encodings like Bitcoin base58, where a leading
+//! `1` is meaningful and must be preserved rather than stripped.
+//!
+//! Note on `BASE32`/`BASE64`/`BASE64_URL_SAFE`: these reuse the *character
+//! repertoires* standardized in RFC 4648, but not the encoding itself —
+//! this crate's big-integer algorithm has no 3-byte/4-char (or 5-byte/8-char)
+//! grouping or `=` padding, so output produced with them is **not**
+//! interoperable with `base32`/`base64` crates or CLI tools. Use them only
+//! when you want a familiar character set for your own base-X round-trips.
+
+/// Bitcoin base58 alphabet (omits `0`, `O`, `I`, `l` to avoid visual ambiguity).
+pub const BASE58_BITCOIN: &str = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// Ripple base58 alphabet (same idea as Bitcoin's, different character order).
+pub const BASE58_RIPPLE: &str = "rpshnaf39wBUDNEGHJKLM4PQRST7VWXYZ2bcdeCg65jkm8oFqi1tuvAxyz";
+
+/// Flickr base58 alphabet (lowercase sorts before uppercase, unlike Bitcoin's).
+pub const BASE58_FLICKR: &str = "123456789abcdefghijkmnopqrstuvwxyzABCDEFGHJKLMNPQRSTUVWXYZ";
+
+/// Base62: digits, uppercase, then lowercase.
+pub const BASE62: &str = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// Base36: digits followed by lowercase letters.
+pub const BASE36: &str = "0123456789abcdefghijklmnopqrstuvwxyz";
+
+/// Base16 (hexadecimal), lowercase.
+pub const BASE16: &str = "0123456789abcdef";
+
+/// RFC 4648's base32 character repertoire, as a base-X alphabet. **Not**
+/// wire-compatible with RFC 4648 base32 (no bit-grouping or `=` padding) —
+/// see the module-level note.
+pub const BASE32: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+/// RFC 4648's base64 character repertoire (standard, `+`/`/`), as a base-X
+/// alphabet. **Not** wire-compatible with RFC 4648 base64 (no bit-grouping
+/// or `=` padding) — see the module-level note.
+pub const BASE64: &str =
+    "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// RFC 4648's base64url character repertoire (URL- and filename-safe,
+/// `-`/`_`), as a base-X alphabet. **Not** wire-compatible with RFC 4648
+/// base64url (no bit-grouping or `=` padding) — see the module-level note.
+pub const BASE64_URL_SAFE: &str =
+    "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
@@ -0,0 +1,166 @@
+//! Const-generic owned output for `no_std` callers.
+//!
+//! The `encode_mut`/`decode_mut` path forces callers to pre-size a
+//! `&mut [u8]` exactly and has no way to report how much of it actually
+//! holds data. [`encode_array`]/[`decode_array`] wrap the same
+//! `BigUintStatic` machinery but hand back an [`ArrayOutput`] that tracks
+//! its own real length, giving `no_std` code a genuine owned result
+//! without `alloc` — pair with [`crate::gen_encoded_size`]/
+//! [`crate::gen_decoded_size`] and [`crate::gen_backing_size`] to compute
+//! `N`/`BACKING` at compile time.
+
+use bigintstatic::BigUintStatic;
+use DecodeError;
+
+/// A fixed-capacity `[u8; N]` buffer that remembers how much of itself
+/// holds real data.
+#[derive(Clone, Copy)]
+pub struct ArrayOutput<const N: usize> {
+    buf: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> ArrayOutput<N> {
+    pub fn as_slice(&self) -> &[u8] {
+        &self.buf[..self.len]
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+/// Encode `input` against an ASCII `alphabet` into an owned, length-tracked
+/// `ArrayOutput<N>`, backed by a `BigUintStatic<BACKING>`.
+pub fn encode_array<const BACKING: usize, const N: usize>(
+    alphabet: &[u8],
+    input: &[u8],
+) -> Result<ArrayOutput<N>, ()> {
+    if !alphabet.is_ascii() {
+        panic!("Alphabet must be ASCII");
+    }
+
+    let mut buf = [0u8; N];
+    if input.is_empty() {
+        return Ok(ArrayOutput { buf, len: 0 });
+    }
+
+    let base = alphabet.len() as u32;
+    if base < 2 {
+        panic!("Alphabet must have at least 2 characters");
+    }
+    let (k, big_base) = ::radix_block(base);
+    let mut big = BigUintStatic::<BACKING>::default();
+    for &byte in input {
+        big.mul_add(256, u32::from(byte)).map_err(|_| ())?;
+    }
+
+    // Pull `k` digits out of the bignum per `div_mod` call, then peel them
+    // off the remainder with cheap native `%`/`/`, same as `encoder::encode`.
+    let mut pos = 0;
+    while !big.is_zero() {
+        let mut rem = big.div_mod(big_base);
+        let block_len = if big.is_zero() {
+            let mut n = 0;
+            let mut r = rem;
+            loop {
+                n += 1;
+                r /= base;
+                if r == 0 {
+                    break;
+                }
+            }
+            n
+        } else {
+            k
+        };
+
+        for _ in 0..block_len {
+            if pos >= N {
+                return Err(());
+            }
+            buf[pos] = alphabet[(rem % base) as usize];
+            rem /= base;
+            pos += 1;
+        }
+    }
+
+    let zeroes = input.iter().take_while(|byte| **byte == 0).count();
+    for _ in 0..zeroes {
+        if pos >= N {
+            return Err(());
+        }
+        buf[pos] = alphabet[0];
+        pos += 1;
+    }
+
+    buf[..pos].reverse();
+    Ok(ArrayOutput { buf, len: pos })
+}
+
+/// Decode an ASCII-`alphabet`-encoded `input` into an owned, length-tracked
+/// `ArrayOutput<N>`, backed by a `BigUintStatic<BACKING>`.
+pub fn decode_array<const BACKING: usize, const N: usize>(
+    alphabet: &[u8],
+    input: &str,
+) -> Result<ArrayOutput<N>, DecodeError> {
+    let mut buf = [0u8; N];
+    if input.is_empty() {
+        return Ok(ArrayOutput { buf, len: 0 });
+    }
+
+    let base = alphabet.len() as u32;
+    let (k, big_base) = ::radix_block(base);
+    let mut big = BigUintStatic::<BACKING>::default();
+
+    const INVALID_INDEX: u8 = 0xFF;
+    let mut lookup = [INVALID_INDEX; 256];
+    for (i, &byte) in alphabet.iter().enumerate() {
+        lookup[byte as usize] = i as u8;
+    }
+
+    // Fold up to `k` digits into a native accumulator before each
+    // `mul_add`, same blocking `decoder::Decoder::decode_mut` uses.
+    let mut acc = 0u32;
+    let mut acc_base = 1u32;
+    let mut count = 0u32;
+
+    for &byte in input.as_bytes() {
+        let index = match lookup[byte as usize] {
+            INVALID_INDEX => return Err(DecodeError::InvalidChar),
+            index => u32::from(index),
+        };
+        acc = acc * base + index;
+        acc_base *= base;
+        count += 1;
+        if count == k {
+            big.mul_add(big_base, acc)
+                .map_err(|_| DecodeError::InvalidChar)?;
+            acc = 0;
+            acc_base = 1;
+            count = 0;
+        }
+    }
+    if count > 0 {
+        big.mul_add(acc_base, acc)
+            .map_err(|_| DecodeError::InvalidChar)?;
+    }
+
+    let value_len = big.be_len();
+    let leaders = input.bytes().take_while(|&b| b == alphabet[0]).count();
+    let len = leaders + value_len;
+    if len > N {
+        return Err(DecodeError::InvalidChar);
+    }
+
+    if value_len > 0 {
+        big.into_bytes_be(&mut buf[leaders..len])
+            .map_err(|_| DecodeError::InvalidChar)?;
+    }
+
+    Ok(ArrayOutput { buf, len })
+}
@@ -0,0 +1,92 @@
+use alloc::vec::Vec;
+
+/// This is a pretty naive implementation of a BigUint abstracting all
+/// math out to a growable `Vec` of `u32` chunks.
+///
+/// Unlike [`BigUintStatic`](crate::bigintstatic::BigUintStatic) this isn't
+/// bound to a fixed backing size, which makes it the right fit for the
+/// `alloc`-gated one-shot `encode`/`decode` path where the final length
+/// isn't known up front.
+///
+/// It can only do a few things:
+/// - Be instantiated with a starting capacity.
+/// - Do a division by `u32`, mutating self and returning the remainder.
+/// - Do a multiplication with addition in one pass.
+/// - Check if it's zero.
+/// - Be written to a `Vec` of big-endian bytes.
+///
+/// Turns out those are all the operations you need to encode and decode
+/// base58, or anything else, really.
+#[derive(Clone, Debug)]
+pub struct BigUint {
+    chunks: Vec<u32>,
+}
+
+impl BigUint {
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        BigUint {
+            chunks: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Divide self by `divider`, return the remainder of the operation.
+    #[inline]
+    pub fn div_mod(&mut self, divider: u32) -> u32 {
+        let mut carry = 0u64;
+
+        for chunk in self.chunks.iter_mut() {
+            carry = (carry << 32) | u64::from(*chunk);
+            *chunk = (carry / u64::from(divider)) as u32;
+            carry %= u64::from(divider);
+        }
+
+        carry as u32
+    }
+
+    /// Perform a multiplication followed by addition. This is a reverse
+    /// of `div_mod` in the sense that when supplied the remainder for
+    /// addition and the same base for multiplication as division, the
+    /// result is the original BigUint. Grows the backing `Vec` by one
+    /// chunk whenever the multiplication overflows.
+    #[inline]
+    pub fn mul_add(&mut self, multiplicator: u32, addition: u32) {
+        let mut carry = u64::from(addition);
+
+        for chunk in self.chunks.iter_mut().rev() {
+            carry += u64::from(*chunk) * u64::from(multiplicator);
+            *chunk = carry as u32;
+            carry >>= 32;
+        }
+
+        if carry > 0 {
+            self.chunks.insert(0, carry as u32);
+        }
+    }
+
+    /// Check if self is zero.
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.chunks.iter().all(|chunk| *chunk == 0)
+    }
+
+    #[inline]
+    pub fn into_bytes_be(self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.chunks.len() * 4);
+        let mut started = false;
+
+        for chunk in &self.chunks {
+            for byte in chunk.to_be_bytes().iter() {
+                if !started {
+                    if *byte == 0 {
+                        continue;
+                    }
+                    started = true;
+                }
+                bytes.push(*byte);
+            }
+        }
+
+        bytes
+    }
+}
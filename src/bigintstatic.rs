@@ -86,6 +86,24 @@ impl<const N: usize> BigUintStatic<N> {
         self.chunks.iter().all(|chunk| *chunk == 0)
     }
 
+    /// Number of big-endian bytes [`Self::into_bytes_be`] would write:
+    /// the backing size minus however many leading all-zero bytes.
+    #[inline]
+    pub fn be_len(&self) -> usize {
+        let mut skip = 0;
+
+        for chunk in self.chunks.iter() {
+            if *chunk != 0 {
+                skip += chunk.leading_zeros() / 8;
+                break;
+            }
+
+            skip += 4;
+        }
+
+        self.chunks.len() * 4 - skip as usize
+    }
+
     #[inline]
     pub fn into_bytes_be<'b>(mut self, output: &'b mut [u8]) -> Result<(), (usize, usize)> {
         let mut skip = 0;
@@ -0,0 +1,106 @@
+//! Optional checksum layer, for address-style encodings like Bitcoin's
+//! Base58Check.
+//!
+//! The crate stays hash-agnostic: callers supply a [`Checksum`] impl
+//! (SHA256d, CRC32, or anything else) rather than pulling in a crypto
+//! dependency here.
+
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+
+use alphabet::Alphabet;
+use DecodeError;
+
+/// A fixed-capacity byte buffer, just large enough to hold the checksum
+/// bytes a [`Checksum`] impl produces.
+#[derive(Clone, Copy)]
+pub struct ArrayVec<const N: usize> {
+    buf: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> ArrayVec<N> {
+    pub fn new() -> Self {
+        ArrayVec {
+            buf: [0u8; N],
+            len: 0,
+        }
+    }
+
+    pub fn push(&mut self, byte: u8) {
+        self.buf[self.len] = byte;
+        self.len += 1;
+    }
+
+    pub fn as_slice(&self) -> &[u8] {
+        &self.buf[..self.len]
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl<const N: usize> Default for ArrayVec<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> From<[u8; N]> for ArrayVec<N> {
+    fn from(buf: [u8; N]) -> Self {
+        ArrayVec { buf, len: N }
+    }
+}
+
+/// Computes the checksum bytes appended to (and verified against) a
+/// payload by [`encode_check`]/[`decode_check`].
+///
+/// The classic instantiation is the first 4 bytes of `SHA256(SHA256(payload))`
+/// (Bitcoin's Base58Check), but the trait lets callers use CRC32 or
+/// anything else.
+///
+/// [`encode_check`] appends however many bytes `digest` returns, but
+/// [`decode_check`] always treats the trailing 4 decoded bytes as the
+/// checksum, so a digest of any other length never verifies.
+pub trait Checksum {
+    fn digest(&self, data: &[u8]) -> ArrayVec<4>;
+}
+
+/// Encode `input` with `checksum.digest(input)` appended before base-X
+/// encoding, so [`decode_check`] can later detect corruption.
+#[cfg(feature = "alloc")]
+pub fn encode_check<A: Alphabet, C: Checksum>(alphabet: A, checksum: &C, input: &[u8]) -> String {
+    let mut payload = Vec::with_capacity(input.len() + 4);
+    payload.extend_from_slice(input);
+    payload.extend_from_slice(checksum.digest(input).as_slice());
+    alphabet.encode(&payload)
+}
+
+/// Base-X decode `input`, split off the trailing checksum bytes, and
+/// verify them against `checksum` recomputed over the remaining payload.
+#[cfg(feature = "alloc")]
+pub fn decode_check<A: Alphabet, C: Checksum>(
+    alphabet: A,
+    checksum: &C,
+    input: &str,
+) -> Result<Vec<u8>, DecodeError> {
+    let mut decoded = alphabet.decode(input)?;
+    if decoded.len() < 4 {
+        return Err(DecodeError::InvalidChecksum);
+    }
+
+    let split_at = decoded.len() - 4;
+    let expected = checksum.digest(&decoded[..split_at]);
+
+    if &decoded[split_at..] != expected.as_slice() {
+        return Err(DecodeError::InvalidChecksum);
+    }
+
+    decoded.truncate(split_at);
+    Ok(decoded)
+}
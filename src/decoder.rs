@@ -24,16 +24,35 @@ where
         }
         let alpha = self.alphabet();
         let base = alpha.len() as u32;
+        let (k, big_base) = ::radix_block(base);
 
         let mut big = BigUint::with_capacity(4);
 
+        // Fold up to `k` digits into a native accumulator before each
+        // bignum pass, so we do one `mul_add` per block of digits
+        // instead of one per digit.
+        let mut acc = 0u32;
+        let mut acc_base = 1u32;
+        let mut count = 0u32;
+
         for c in Self::iter(input) {
             if let Some(carry) = self.carry(c) {
-                big.mul_add(base, carry);
+                acc = acc * base + carry;
+                acc_base *= base;
+                count += 1;
+                if count == k {
+                    big.mul_add(big_base, acc);
+                    acc = 0;
+                    acc_base = 1;
+                    count = 0;
+                }
             } else {
-                return Err(DecodeError);
+                return Err(DecodeError::InvalidChar);
             }
         }
+        if count > 0 {
+            big.mul_add(acc_base, acc);
+        }
 
         let mut bytes = big.into_bytes_be();
 
@@ -58,24 +77,39 @@ where
         }
         let alpha = self.alphabet();
         let base = alpha.len() as u32;
+        let (k, big_base) = ::radix_block(base);
 
         let mut big = BigUintStatic::<BACKING>::default();
 
+        let mut acc = 0u32;
+        let mut acc_base = 1u32;
+        let mut count = 0u32;
+
         for c in Self::iter(input) {
             if let Some(carry) = self.carry(c) {
-                match big.mul_add(base, carry) {
-                    Ok(_) => (),
-                    Err(_) => return Err(DecodeError),
+                acc = acc * base + carry;
+                acc_base *= base;
+                count += 1;
+                if count == k {
+                    if big.mul_add(big_base, acc).is_err() {
+                        return Err(DecodeError::InvalidChar);
+                    }
+                    acc = 0;
+                    acc_base = 1;
+                    count = 0;
                 }
             } else {
-                return Err(DecodeError);
+                return Err(DecodeError::InvalidChar);
             }
         }
+        if count > 0 && big.mul_add(acc_base, acc).is_err() {
+            return Err(DecodeError::InvalidChar);
+        }
 
         //TODO better error handling
         match big.into_bytes_be(output) {
             Ok(_) => (),
-            Err(_) => return Err(DecodeError),
+            Err(_) => return Err(DecodeError::InvalidChar),
         }
 
         let leader = alpha[0];
@@ -129,8 +163,33 @@ impl<'a, 'b> Decoder<'a, 'b> for U8Decoder<'b> {
     }
 }
 
-pub(crate) struct CharDecoder<'b>(pub &'b [char]);
+/// A `char`-keyed decoder for non-ASCII alphabets. `carry` is resolved by
+/// binary search over a `(char, u32)` table sorted once in [`Self::new`],
+/// rather than a linear scan per input `char` — `alphabet()` still returns
+/// the original, unsorted slice so insertion order (and `alpha[0]` as the
+/// leading-zero digit) is preserved.
+#[cfg(feature = "alloc")]
+pub(crate) struct CharDecoder<'b> {
+    alphabet: &'b [char],
+    table: Vec<(char, u32)>,
+}
 
+#[cfg(feature = "alloc")]
+impl<'b> CharDecoder<'b> {
+    #[inline]
+    pub(crate) fn new(alphabet: &'b [char]) -> Self {
+        let mut table: Vec<(char, u32)> = alphabet
+            .iter()
+            .enumerate()
+            .map(|(i, &c)| (c, i as u32))
+            .collect();
+        table.sort_unstable_by_key(|&(c, _)| c);
+
+        CharDecoder { alphabet, table }
+    }
+}
+
+#[cfg(feature = "alloc")]
 impl<'a, 'b> Decoder<'a, 'b> for CharDecoder<'b> {
     type Iter = core::str::Chars<'a>;
 
@@ -140,17 +199,16 @@ impl<'a, 'b> Decoder<'a, 'b> for CharDecoder<'b> {
     }
     #[inline]
     fn carry(&self, c: char) -> Option<u32> {
-        self.0
-            .iter()
-            .enumerate()
-            .find(|&(_, ch)| *ch == c)
-            .map(|(i, _)| i as u32)
+        self.table
+            .binary_search_by_key(&c, |&(ch, _)| ch)
+            .ok()
+            .map(|idx| self.table[idx].1)
     }
     #[inline]
     fn alphabet<'c>(&self) -> &'c [char]
     where
         'b: 'c,
     {
-        self.0
+        self.alphabet
     }
 }
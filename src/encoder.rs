@@ -0,0 +1,119 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use bigint::BigUint;
+use bigintstatic::BigUintStatic;
+
+/// Encode `input` into a sequence of alphabet digits, least-significant
+/// digit first. Callers are responsible for reversing the result (and,
+/// for `&str` alphabets, for turning it into a `String`).
+#[cfg(feature = "alloc")]
+#[inline]
+pub(crate) fn encode<T: Copy>(alphabet: &[T], input: &[u8]) -> Vec<T> {
+    if input.is_empty() {
+        return Vec::new();
+    }
+
+    let base = alphabet.len() as u32;
+    if base < 2 {
+        panic!("Alphabet must have at least 2 characters");
+    }
+    let (k, big_base) = ::radix_block(base);
+    let mut big = BigUint::with_capacity(4);
+
+    for &byte in input {
+        big.mul_add(256, u32::from(byte));
+    }
+
+    let mut out = Vec::with_capacity(input.len() * 138 / 100 + 1);
+
+    // Pull `k` digits out of the bignum per `div_mod` call, then peel
+    // them off the remainder with cheap native `%`/`/`. The final block
+    // is the only one that needs trimming down to its real digit count.
+    while !big.is_zero() {
+        let mut rem = big.div_mod(big_base);
+        if big.is_zero() {
+            loop {
+                out.push(alphabet[(rem % base) as usize]);
+                rem /= base;
+                if rem == 0 {
+                    break;
+                }
+            }
+        } else {
+            for _ in 0..k {
+                out.push(alphabet[(rem % base) as usize]);
+                rem /= base;
+            }
+        }
+    }
+
+    let zeroes = input.iter().take_while(|byte| **byte == 0).count();
+    for _ in 0..zeroes {
+        out.push(alphabet[0]);
+    }
+
+    out
+}
+
+/// Encode `input` into `output`, least-significant digit first, using a
+/// fixed-size [`BigUintStatic`] backing so it works without `alloc`.
+///
+/// WARNING: `BACKING` is the size of the backing `[u32]`; use
+/// [`crate::gen_backing_size`] to calculate the right value.
+#[inline]
+pub(crate) fn encode_mut<T: Copy + Into<u8>, const BACKING: usize>(
+    alphabet: &[T],
+    output: &mut [u8],
+    input: &[u8],
+) -> Result<(), ()> {
+    if input.is_empty() {
+        return Ok(());
+    }
+
+    let base = alphabet.len() as u32;
+    if base < 2 {
+        panic!("Alphabet must have at least 2 characters");
+    }
+    let (k, big_base) = ::radix_block(base);
+    let mut big = BigUintStatic::<BACKING>::from_bytes_be(input).map_err(|_| ())?;
+
+    let mut pos = 0;
+    while !big.is_zero() {
+        let mut rem = big.div_mod(big_base);
+        let block_len = if big.is_zero() {
+            let mut n = 0;
+            let mut r = rem;
+            loop {
+                n += 1;
+                r /= base;
+                if r == 0 {
+                    break;
+                }
+            }
+            n
+        } else {
+            k
+        };
+
+        for _ in 0..block_len {
+            if pos >= output.len() {
+                return Err(());
+            }
+            output[pos] = alphabet[(rem % base) as usize].into();
+            rem /= base;
+            pos += 1;
+        }
+    }
+
+    let zeroes = input.iter().take_while(|byte| **byte == 0).count();
+    for _ in 0..zeroes {
+        if pos >= output.len() {
+            return Err(());
+        }
+        output[pos] = alphabet[0].into();
+        pos += 1;
+    }
+
+    Ok(())
+}
@@ -34,20 +34,36 @@ extern crate std;
 // use alloc::{string::String, vec::Vec};
 
 pub mod alphabet;
+pub mod array;
 #[cfg(feature = "alloc")]
 mod bigint;
 mod bigintstatic;
+#[cfg(feature = "alloc")]
+pub mod checksum;
 pub mod decoder;
 pub mod encoder;
+#[cfg(feature = "alloc")]
+pub mod stream;
 
 pub use alphabet::Alphabet;
 
-#[derive(Debug)]
-pub struct DecodeError;
+#[derive(Debug, PartialEq, Eq)]
+pub enum DecodeError {
+    /// An input character wasn't part of the given alphabet. Also returned
+    /// by `decode_mut` and [`array::decode_array`] when the decoded value
+    /// doesn't fit the fixed-size backing or output buffer.
+    InvalidChar,
+    /// The trailing checksum didn't match the one recomputed over the
+    /// decoded payload. Only produced by [`checksum::decode_check`].
+    InvalidChecksum,
+}
 
 impl core::fmt::Display for DecodeError {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
-        write!(f, "Failed to decode the given data")
+        match self {
+            DecodeError::InvalidChar => write!(f, "Failed to decode the given data"),
+            DecodeError::InvalidChecksum => write!(f, "Checksum mismatch while decoding"),
+        }
     }
 }
 
@@ -92,6 +108,34 @@ pub const fn gen_decoded_size(base: usize, input_byte_size: usize) -> usize {
     (input_byte_size as f64 * (log10(base) / log10(256))) as usize //might need to + 1 here maybe
 }
 
+/// Find the largest `k` such that `base^k` still fits in a `u32`, and
+/// return it along with `BIG_BASE = base^k`.
+///
+/// Folding `k` alphabet digits into one `u32` before touching the bignum
+/// turns what would be one bignum pass per input digit into one bignum
+/// pass per `k` digits, cutting the number of O(bignum length) passes
+/// roughly `k`-fold.
+pub(crate) fn radix_block(base: u32) -> (u32, u32) {
+    // `base < 2` can't make progress (`big_base` would never grow past
+    // `base`, so the loop below would never terminate) and isn't a
+    // meaningful alphabet size anyway; return `(1, base)` straight away
+    // instead of spinning.
+    if base < 2 {
+        return (1, base);
+    }
+
+    let max = u64::from(u32::MAX);
+    let mut k = 1u32;
+    let mut big_base = u64::from(base);
+
+    while big_base * u64::from(base) <= max {
+        big_base *= u64::from(base);
+        k += 1;
+    }
+
+    (k, big_base as u32)
+}
+
 // https://stackoverflow.com/questions/35968963/trying-to-calculate-logarithm-base-10-without-math-h-really-close-just-having
 const fn ln(x: usize) -> f64 {
     let mut old_sum = 0.0;
@@ -119,8 +163,16 @@ const fn log10(x: usize) -> f64 {
 #[cfg(test)]
 mod test {
 
+    use super::alphabet::constants::*;
+    use super::array::{decode_array, encode_array};
+    use super::checksum::{decode_check, encode_check, ArrayVec, Checksum};
     use super::decode;
     use super::encode;
+    use super::radix_block;
+    use super::decoder::{CharDecoder, Decoder as _};
+    use super::encoder;
+    use super::stream::{Decoder, Encoder};
+    use super::DecodeError;
     use super::{gen_decoded_size, gen_encoded_size};
 
     extern crate json;
@@ -129,6 +181,7 @@ mod test {
     use std::fs::File;
     use std::io::Read;
     use std::string::String;
+    use std::vec::Vec;
 
     #[test]
     fn works() {
@@ -156,6 +209,79 @@ mod test {
         }
     }
 
+    #[test]
+    fn alphabet_constants_roundtrip() {
+        let input = b"\x00\x00the quick brown fox jumps over the lazy dog";
+
+        for alphabet in &[
+            BASE58_BITCOIN,
+            BASE58_RIPPLE,
+            BASE58_FLICKR,
+            BASE62,
+            BASE36,
+            BASE16,
+            BASE32,
+            BASE64,
+            BASE64_URL_SAFE,
+        ] {
+            let encoded = encode(*alphabet, input);
+            let decoded = decode(*alphabet, &encoded).unwrap();
+            assert_eq!(decoded, input, "alphabet {:?} failed to roundtrip", alphabet);
+        }
+    }
+
+    struct SumCheck;
+
+    impl Checksum for SumCheck {
+        fn digest(&self, data: &[u8]) -> ArrayVec<4> {
+            let sum = data.iter().fold(0u32, |acc, &b| acc.wrapping_add(u32::from(b)));
+            ArrayVec::from(sum.to_be_bytes())
+        }
+    }
+
+    #[test]
+    fn checksum_roundtrip() {
+        let alphabet = BASE58_BITCOIN;
+        let payload = b"\x00hello, checksummed world!";
+
+        let encoded = encode_check(alphabet, &SumCheck, payload);
+        let decoded = decode_check(alphabet, &SumCheck, &encoded).unwrap();
+        assert_eq!(decoded, payload);
+    }
+
+    #[test]
+    fn checksum_rejects_corrupted_input() {
+        let alphabet = BASE58_BITCOIN;
+        let payload = b"hello, checksummed world!";
+
+        let mut encoded = encode_check(alphabet, &SumCheck, payload);
+        // Flip the last character, which falls within the checksum digits.
+        let last = encoded.pop().unwrap();
+        let replacement = alphabet.chars().find(|&c| c != last).unwrap();
+        encoded.push(replacement);
+
+        assert_eq!(
+            decode_check(alphabet, &SumCheck, &encoded),
+            Err(DecodeError::InvalidChecksum)
+        );
+    }
+
+    #[test]
+    fn char_decoder_roundtrips_unicode_alphabet() {
+        // A wider-than-two-symbol alphabet, with insertion order scrambled
+        // relative to `char` sort order, so this actually exercises the
+        // binary-search table built in `CharDecoder::new` rather than just
+        // its first two entries.
+        let alphabet: Vec<char> = "世界αβγ😀😐zyx".chars().collect();
+
+        let input = b"\x00\x00the quick brown fox";
+        let digits = encoder::encode(&alphabet, input);
+        let encoded: String = digits.iter().rev().collect();
+
+        let decoded = CharDecoder::new(&alphabet).decode(&encoded).unwrap();
+        assert_eq!(decoded, input);
+    }
+
     #[test]
     fn gen_works() {
         let input = [
@@ -216,4 +342,91 @@ mod test {
         );
         assert_eq!(decoded, &[0xff, 0x00, 0xff, 0x00]);
     }
+
+    #[test]
+    fn radix_block_rejects_degenerate_base() {
+        // `base < 2` can't grow `big_base` past `base`, so the loop used to
+        // spin forever instead of erroring out; it should now return
+        // immediately with `k == 1`.
+        assert_eq!(radix_block(0), (1, 0));
+        assert_eq!(radix_block(1), (1, 1));
+    }
+
+    #[test]
+    fn radix_block_big_base_never_truncates_to_zero() {
+        // Bases that divide 2^32 evenly (e.g. 2) used to let `big_base`
+        // grow to exactly 2^32, which truncates to 0 as a `u32`.
+        for base in 2..260 {
+            let (k, big_base) = radix_block(base);
+            assert_ne!(big_base, 0, "base {} produced a zero BIG_BASE", base);
+            assert_eq!(u64::from(big_base), u64::from(base).pow(k));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn empty_alphabet_fails_fast_instead_of_hanging() {
+        let _ = encode("", &[1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn single_char_alphabet_fails_fast_instead_of_hanging() {
+        // `base == 1` can't ever divide a non-zero bignum down to zero, so
+        // `while !big.is_zero() { big.div_mod(big_base) }` used to spin
+        // forever instead of erroring out.
+        let _ = encode("a", &[1u8]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn single_char_alphabet_encode_mut_fails_fast() {
+        let mut output = [0u8; 8];
+        let _ = super::encode_mut::<_, 1>("a", &mut output, &[1u8]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn single_char_alphabet_encode_array_fails_fast() {
+        let _ = encode_array::<1, 8>(b"a", &[1u8]);
+    }
+
+    #[test]
+    fn chunked_stream_matches_one_shot() {
+        let alphabet = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+        let input = b"\x00\x00hello, streaming world!";
+
+        let mut enc = Encoder::new(alphabet);
+        enc.update(&input[..5]);
+        enc.update(&input[5..]);
+        let chunked = enc.finalize();
+        assert_eq!(chunked, encode(alphabet, input));
+
+        let mut dec = Decoder::new(alphabet);
+        dec.update(&chunked[..4]).unwrap();
+        dec.update(&chunked[4..]).unwrap();
+        assert_eq!(dec.finalize().unwrap(), input);
+    }
+
+    #[test]
+    fn array_roundtrip_multi_block() {
+        let alphabet = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+        let input: [u8; 40] = core::array::from_fn(|i| i as u8);
+
+        let encoded = encode_array::<16, 64>(alphabet, &input).unwrap();
+        let encoded_str = core::str::from_utf8(encoded.as_slice()).unwrap();
+        assert_eq!(encoded_str, encode(&alphabet[..], &input));
+
+        let decoded = decode_array::<16, 64>(alphabet, encoded_str).unwrap();
+        assert_eq!(decoded.as_slice(), &input[..]);
+    }
+
+    #[test]
+    fn decode_array_rejects_undersized_n() {
+        let alphabet = b"0123456789";
+        let encoded = encode_array::<8, 24>(alphabet, &[1, 2, 3, 4, 5, 6, 7, 8]).unwrap();
+        let encoded_str = core::str::from_utf8(encoded.as_slice()).unwrap();
+
+        assert!(decode_array::<4, 2>(alphabet, encoded_str).is_err());
+    }
 }
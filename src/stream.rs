@@ -0,0 +1,195 @@
+//! Incremental, stateful encoding and decoding.
+//!
+//! Unlike the one-shot [`encode`](crate::encode)/[`decode`](crate::decode)
+//! functions, [`Encoder`] and [`Decoder`] accept input in successive
+//! fragments via `update`, so callers reading from a `Read` (or writing to
+//! a `Write`) don't need a contiguous in-memory slice up front. This
+//! mirrors the stream-append pattern of RLP's `RlpStream`.
+//!
+//! [`Decoder`] is genuinely incremental: each `update` folds its fragment
+//! straight into a persistent bignum. [`Encoder`] is not — base-X encoding
+//! has to see the whole payload before it can emit the first digit, so
+//! `update` just appends to an internal buffer and the real encoding work
+//! happens once, in `finalize`. Its memory behavior is the same as
+//! buffering the input yourself and calling [`encode`](crate::encode);
+//! `update`/`finalize` are offered for a consistent API with [`Decoder`].
+
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, string::String, vec::Vec};
+
+use alphabet::Alphabet;
+use bigint::BigUint;
+use DecodeError;
+
+/// A chunk-friendly base-X encoder.
+///
+/// Feed input bytes with [`update`](Encoder::update), then call
+/// [`finalize`](Encoder::finalize) once to get the encoded string. `update`
+/// just buffers its input; see the module docs for why encoding can't
+/// start until `finalize`.
+#[cfg(feature = "alloc")]
+pub struct Encoder<'a> {
+    alphabet: &'a str,
+    input: Vec<u8>,
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> Encoder<'a> {
+    pub fn new(alphabet: &'a str) -> Self {
+        Encoder {
+            alphabet,
+            input: Vec::new(),
+        }
+    }
+
+    /// Append another chunk of bytes to be encoded.
+    pub fn update(&mut self, part: &[u8]) {
+        self.input.extend_from_slice(part);
+    }
+
+    /// Encode everything fed so far and consume the encoder.
+    pub fn finalize(self) -> String {
+        self.alphabet.encode(&self.input)
+    }
+}
+
+/// Character-to-digit lookup for [`Decoder`], built once in [`Decoder::new`].
+///
+/// Mirrors the two [`crate::decoder::Decoder`] impls: a dense 256-entry
+/// table for ASCII alphabets (same idea as
+/// [`U8Decoder`](crate::decoder::U8Decoder)), or a `(char, u32)` table
+/// sorted once up front and probed with binary search for non-ASCII ones
+/// (same idea as [`CharDecoder`](crate::decoder::CharDecoder)).
+#[cfg(feature = "alloc")]
+enum Lookup {
+    Bytes(Box<[u8; 256]>),
+    Chars(Vec<(char, u32)>),
+}
+
+#[cfg(feature = "alloc")]
+impl Lookup {
+    fn new(alphabet: &str) -> Self {
+        if alphabet.is_ascii() {
+            const INVALID_INDEX: u8 = 0xFF;
+            let mut table = Box::new([INVALID_INDEX; 256]);
+            for (i, byte) in alphabet.bytes().enumerate() {
+                table[byte as usize] = i as u8;
+            }
+            Lookup::Bytes(table)
+        } else {
+            let mut table: Vec<(char, u32)> = alphabet
+                .chars()
+                .enumerate()
+                .map(|(i, c)| (c, i as u32))
+                .collect();
+            table.sort_unstable_by_key(|&(c, _)| c);
+            Lookup::Chars(table)
+        }
+    }
+
+    #[inline]
+    fn carry(&self, c: char) -> Option<u32> {
+        match self {
+            Lookup::Bytes(table) => {
+                if c.is_ascii() {
+                    match table[c as usize] {
+                        0xFF => None,
+                        index => Some(u32::from(index)),
+                    }
+                } else {
+                    None
+                }
+            }
+            Lookup::Chars(table) => table
+                .binary_search_by_key(&c, |&(ch, _)| ch)
+                .ok()
+                .map(|idx| table[idx].1),
+        }
+    }
+}
+
+/// An incremental base-X decoder.
+///
+/// Feed input fragments with [`update`](Decoder::update), then call
+/// [`finalize`](Decoder::finalize) once to get the decoded bytes.
+#[cfg(feature = "alloc")]
+pub struct Decoder<'a> {
+    base: u32,
+    k: u32,
+    big_base: u32,
+    lookup: Lookup,
+    leader: char,
+    big: BigUint,
+    acc: u32,
+    acc_base: u32,
+    count: u32,
+    leading_zeros: usize,
+    past_leading_zeros: bool,
+    _alphabet: core::marker::PhantomData<&'a str>,
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> Decoder<'a> {
+    pub fn new(alphabet: &'a str) -> Self {
+        let leader = alphabet.chars().next().expect("alphabet must not be empty");
+        let base = alphabet.chars().count() as u32;
+        let (k, big_base) = ::radix_block(base);
+        Decoder {
+            base,
+            k,
+            big_base,
+            lookup: Lookup::new(alphabet),
+            leader,
+            big: BigUint::with_capacity(4),
+            acc: 0,
+            acc_base: 1,
+            count: 0,
+            leading_zeros: 0,
+            past_leading_zeros: false,
+            _alphabet: core::marker::PhantomData,
+        }
+    }
+
+    /// Feed another chunk of the encoded string.
+    ///
+    /// Folds up to `k` digits into a native accumulator before each
+    /// `mul_add` on the persistent bignum, the same blocking [`crate::radix_block`]
+    /// uses for the one-shot decoders, so this stays on the fast path across
+    /// `update` calls instead of doing one bignum pass per character.
+    pub fn update(&mut self, part: &str) -> Result<(), DecodeError> {
+        for c in part.chars() {
+            if !self.past_leading_zeros {
+                if c == self.leader {
+                    self.leading_zeros += 1;
+                    continue;
+                }
+                self.past_leading_zeros = true;
+            }
+
+            let carry = self.lookup.carry(c).ok_or(DecodeError::InvalidChar)?;
+            self.acc = self.acc * self.base + carry;
+            self.acc_base *= self.base;
+            self.count += 1;
+            if self.count == self.k {
+                self.big.mul_add(self.big_base, self.acc);
+                self.acc = 0;
+                self.acc_base = 1;
+                self.count = 0;
+            }
+        }
+        Ok(())
+    }
+
+    /// Decode everything fed so far and consume the decoder.
+    pub fn finalize(mut self) -> Result<Vec<u8>, DecodeError> {
+        if self.count > 0 {
+            self.big.mul_add(self.acc_base, self.acc);
+        }
+
+        let mut bytes = self.big.into_bytes_be();
+        for _ in 0..self.leading_zeros {
+            bytes.insert(0, 0);
+        }
+        Ok(bytes)
+    }
+}